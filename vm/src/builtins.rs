@@ -33,33 +33,119 @@ macro_rules! arg_count_check {
     };
 }
 
-fn get_locals(vm: &mut VirtualMachine) -> PyObjectRef {
-    let d = vm.new_dict();
-    // TODO: implement dict_iter_items?
-    let locals = vm.get_locals();
-    match locals.borrow().kind {
-        PyObjectKind::Dict { ref elements } => {
-            for l in elements {
-                d.set_item(l.0, l.1.clone());
+impl PyFuncArgs {
+    fn get_kwarg(&self, name: &str) -> Option<PyObjectRef> {
+        self.kwargs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Binds positional and keyword arguments onto a list of named parameters,
+    /// filling in defaults for the ones that were left out. Raises a `TypeError`
+    /// for missing required arguments, unknown keywords, or an argument given
+    /// both positionally and by keyword.
+    pub fn bind(
+        &self,
+        vm: &mut VirtualMachine,
+        params: &[(&str, Option<PyObjectRef>)],
+    ) -> Result<HashMap<String, PyObjectRef>, PyObjectRef> {
+        let mut bound = HashMap::new();
+        let mut positional = self.args.iter();
+
+        for (name, default) in params {
+            let value = match (positional.next(), self.get_kwarg(name)) {
+                (Some(_), Some(_)) => {
+                    return Err(vm.new_type_error(format!(
+                        "Got multiple values for argument '{}'",
+                        name
+                    )))
+                }
+                (Some(value), None) => value.clone(),
+                (None, Some(value)) => value,
+                (None, None) => match default {
+                    Some(default) => default.clone(),
+                    None => {
+                        return Err(
+                            vm.new_type_error(format!("Missing required argument: '{}'", name))
+                        )
+                    }
+                },
+            };
+            bound.insert(name.to_string(), value);
+        }
+
+        if positional.next().is_some() {
+            return Err(vm.new_type_error("Too many positional arguments".to_string()));
+        }
+
+        let known: Vec<&str> = params.iter().map(|(name, _)| *name).collect();
+        for (name, _) in &self.kwargs {
+            if !known.contains(&name.as_str()) {
+                return Err(vm.new_type_error(format!("Unexpected keyword argument: '{}'", name)));
             }
         }
-        _ => {}
+
+        Ok(bound)
+    }
+}
+
+fn object_attribute_names(obj: &PyObjectRef) -> Vec<String> {
+    // Instances and classes don't store their attributes directly in their
+    // own `kind`; they hold a nested `__dict__` object that the attribute
+    // protocol (`get_attr`/`set_attr`) reads and writes through. Plain dicts
+    // (e.g. the raw locals mapping) are their own backing store.
+    let dict = match obj.borrow().kind {
+        PyObjectKind::Dict { .. } => Some(obj.clone()),
+        PyObjectKind::Class { ref dict, .. } => Some(dict.clone()),
+        PyObjectKind::Instance { ref dict } => Some(dict.clone()),
+        _ => None,
     };
-    d
+
+    match dict {
+        Some(dict) => match dict.borrow().kind {
+            PyObjectKind::Dict { ref elements } => elements.keys().cloned().collect(),
+            _ => Vec::new(),
+        },
+        None => Vec::new(),
+    }
+}
+
+fn sorted_name_list(vm: &mut VirtualMachine, mut names: Vec<String>) -> PyObjectRef {
+    names.sort();
+    names.dedup();
+    let elements: Vec<PyObjectRef> = names.into_iter().map(|name| vm.new_str(name)).collect();
+    vm.context().new_list(elements)
 }
 
 fn dir_locals(vm: &mut VirtualMachine) -> PyObjectRef {
-    get_locals(vm)
+    let locals = vm.get_locals();
+    let names = object_attribute_names(&locals);
+    sorted_name_list(vm, names)
 }
 
-fn dir_object(vm: &mut VirtualMachine, _obj: PyObjectRef) -> PyObjectRef {
-    let d = vm.new_dict();
-    // TODO: loop over dict of instance, next of class?
-    // TODO: Implement dir for objects
-    // for i in obj.iter_items() {
-    //    d.set_item(k, v);
-    // }
-    d
+fn dir_object(vm: &mut VirtualMachine, obj: PyObjectRef) -> PyObjectRef {
+    // Collect names from the instance's own __dict__, then walk the MRO so
+    // that attributes defined on the type or any of its bases show up too.
+    let mut names = object_attribute_names(&obj);
+
+    // `dir()` on a class should walk the class's own bases, not its
+    // metaclass's — `obj.typ` on a class object is the metaclass (usually
+    // `type`), so using it here would surface `type`/`object` attributes
+    // instead of the ones inherited from the class's actual Python bases.
+    let is_class = match obj.borrow().kind {
+        PyObjectKind::Class { .. } => true,
+        _ => false,
+    };
+    let mro_start = if is_class { Some(obj.clone()) } else { obj.borrow().typ.clone() };
+
+    if let Some(start) = mro_start {
+        for class in objtype::mro(&start) {
+            names.extend(object_attribute_names(&class));
+        }
+    }
+
+    sorted_name_list(vm, names)
 }
 
 // builtin_abs
@@ -113,13 +199,29 @@ fn builtin_chr(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
 // builtin_classmethod
 
 fn builtin_compile(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
-    arg_count_check!(vm, args, 1, -1);
-    // TODO:
-    let mode = compile::Mode::Eval;
+    // compile(source, filename, mode, flags=0, dont_inherit=False, optimize=-1)
+    arg_count_check!(vm, args, 3, 6);
     let source = args.args[0].borrow().str();
+    let filename = args.args[1].borrow().str();
+    let mode_str = args.args[2].borrow().str();
 
-    match compile::compile(vm, &source, mode, None) {
-        Ok(value) => Ok(value),
+    let mode = match mode_str.as_str() {
+        "eval" => compile::Mode::Eval,
+        "exec" => compile::Mode::Exec,
+        "single" => compile::Mode::Single,
+        other => {
+            return Err(vm.new_type_error(format!(
+                "compile() mode must be 'exec', 'eval' or 'single', not '{}'",
+                other
+            )))
+        }
+    };
+
+    // flags, dont_inherit and optimize are accepted for signature compatibility
+    // but have no effect yet.
+
+    match compile::compile(vm, &source, mode, Some(filename)) {
+        Ok(code_obj) => Ok(code_obj),
         Err(msg) => Err(vm.new_type_error(msg)),
     }
 }
@@ -127,6 +229,44 @@ fn builtin_compile(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
 // builtin_complex
 // builtin_delattr
 
+fn make_scope(locals: PyObjectRef, globals: PyObjectRef, builtins: PyObjectRef) -> PyObjectRef {
+    let globals_scope = PyObject {
+        kind: PyObjectKind::Scope {
+            scope: Scope {
+                locals: globals,
+                parent: Some(builtins),
+            },
+        },
+        typ: None,
+    }.into_ref();
+    PyObject {
+        kind: PyObjectKind::Scope {
+            scope: Scope {
+                locals: locals,
+                parent: Some(globals_scope),
+            },
+        },
+        typ: None,
+    }.into_ref()
+}
+
+fn code_from_source(vm: &mut VirtualMachine, source: PyObjectRef, mode: compile::Mode) -> PyResult {
+    let is_string = match source.borrow().kind {
+        PyObjectKind::String { .. } => true,
+        _ => false,
+    };
+    if is_string {
+        let value = source.borrow().str();
+        match compile::compile(vm, &value, mode, None) {
+            Ok(code_obj) => Ok(code_obj),
+            Err(msg) => Err(vm.new_type_error(msg)),
+        }
+    } else {
+        // Assume it is already a code object.
+        Ok(source)
+    }
+}
+
 fn builtin_dir(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     if args.args.is_empty() {
         Ok(dir_locals(vm))
@@ -137,34 +277,87 @@ fn builtin_dir(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
 }
 
 // builtin_divmod
-// builtin_enumerate
 
-fn builtin_eval(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
-    arg_count_check!(vm, args, 3);
-    let args = args.args;
-    // TODO: handle optional global and locals
-    let source = args[0].clone();
-    let _globals = args[1].clone();
-    let locals = args[2].clone();
+fn builtin_enumerate(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_count_check!(vm, args, 1, 2);
+    let iterable = args.args[0].clone();
+    let start = if args.args.len() > 1 {
+        match args.args[1].borrow().kind {
+            PyObjectKind::Integer { value } => value,
+            _ => return Err(vm.new_type_error("enumerate() start must be an integer".to_string())),
+        }
+    } else {
+        0
+    };
 
-    let code_obj = source; // if source.borrow().kind
+    let iterator = builtin_iter(vm, PyFuncArgs { args: vec![iterable], kwargs: vec![] })?;
+    Ok(PyObject::new(
+        PyObjectKind::EnumerateIterator {
+            counter: start,
+            iterator,
+        },
+        vm.context().iter_type.clone(),
+    ))
+}
+
+fn run_eval_exec(vm: &mut VirtualMachine, args: PyFuncArgs, mode: compile::Mode) -> PyResult {
+    // eval(source, globals=None, locals=None) / exec(source, globals=None, locals=None);
+    // an explicit `None` for globals/locals means the same thing as omitting
+    // it, same as CPython.
+    let none = vm.get_none();
+    let bound = args.bind(
+        vm,
+        &[
+            ("source", None),
+            ("globals", Some(none.clone())),
+            ("locals", Some(none)),
+        ],
+    )?;
 
-    // Construct new scope:
-    let scope_inner = Scope {
-        locals: locals,
-        parent: None,
+    let source = bound["source"].clone();
+    let globals = match bound["globals"].borrow().kind {
+        PyObjectKind::None => vm.get_globals(),
+        _ => bound["globals"].clone(),
     };
-    let scope = PyObject {
-        kind: PyObjectKind::Scope { scope: scope_inner },
-        typ: None,
-    }.into_ref();
+    let locals = match bound["locals"].borrow().kind {
+        PyObjectKind::None => globals.clone(),
+        _ => bound["locals"].clone(),
+    };
+
+    let code_obj = code_from_source(vm, source, mode)?;
+    let builtins = vm.get_builtin_scope();
+    let scope = make_scope(locals, globals, builtins);
+
+    let value = vm.run_code_obj(code_obj, scope)?;
+    match mode {
+        compile::Mode::Exec => Ok(vm.get_none()),
+        _ => Ok(value),
+    }
+}
+
+fn builtin_eval(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    run_eval_exec(vm, args, compile::Mode::Eval)
+}
+
+fn builtin_exec(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    run_eval_exec(vm, args, compile::Mode::Exec)
+}
+
+fn builtin_filter(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_count_check!(vm, args, 2);
+    let predicate = args.args[0].clone();
+    let iterable = args.args[1].clone();
 
-    // Run the source:
-    vm.run_code_obj(code_obj, scope)
+    let iterator = builtin_iter(vm, PyFuncArgs { args: vec![iterable], kwargs: vec![] })?;
+    Ok(PyObject::new(
+        PyObjectKind::FilterIterator {
+            predicate,
+            iterator,
+        },
+        vm.context().iter_type.clone(),
+    ))
 }
 
-// builtin_exec
-// builtin_filter
 // builtin_float
 // builtin_format
 // builtin_frozenset
@@ -223,7 +416,209 @@ fn builtin_isinstance(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
 }
 
 // builtin_issubclass
-// builtin_iter
+
+fn builtin_iter(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_count_check!(vm, args, 1);
+    let iterated_obj = args.args[0].clone();
+
+    let iter_method_name = "__iter__".to_string();
+    if let Ok(iter_method) = vm.get_attribute(iterated_obj.clone(), &iter_method_name) {
+        return vm.invoke(iter_method, PyFuncArgs::default());
+    }
+
+    let is_iterable = match iterated_obj.borrow().kind {
+        PyObjectKind::Range { .. }
+        | PyObjectKind::Tuple { .. }
+        | PyObjectKind::List { .. }
+        | PyObjectKind::String { .. } => true,
+        _ => false,
+    };
+    if !is_iterable {
+        return Err(vm.new_type_error(format!("{:?} object is not iterable", iterated_obj)));
+    }
+
+    Ok(PyObject::new(
+        PyObjectKind::Iterator {
+            position: 0,
+            iterated_obj,
+        },
+        vm.context().iter_type.clone(),
+    ))
+}
+
+fn builtin_next(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_count_check!(vm, args, 1);
+    let iter_obj = args.args[0].clone();
+
+    let next_method_name = "__next__".to_string();
+    if let Ok(next_method) = vm.get_attribute(iter_obj.clone(), &next_method_name) {
+        return vm.invoke(next_method, PyFuncArgs::default());
+    }
+
+    next_builtin_iterator(vm, iter_obj)
+}
+
+fn is_stop_iteration(vm: &mut VirtualMachine, err: &PyObjectRef) -> bool {
+    objtype::isinstance(err.clone(), vm.context().exceptions.stop_iteration.clone())
+}
+
+/// Advances `iterator` and turns a `StopIteration` into `Ok(None)`. Any other
+/// error propagates via `?` instead of being mistaken for exhaustion.
+fn advance_or_stop(
+    vm: &mut VirtualMachine,
+    iterator: &PyObjectRef,
+) -> Result<Option<PyObjectRef>, PyObjectRef> {
+    match next_builtin_iterator(vm, iterator.clone()) {
+        Ok(value) => Ok(Some(value)),
+        Err(err) => {
+            if is_stop_iteration(vm, &err) {
+                Ok(None)
+            } else {
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Advances one of the built-in iterator kinds (enumerate, zip, map, filter,
+/// or the generic index-based iterator over a range or sequence), raising
+/// `StopIteration` once the underlying source is exhausted.
+fn next_builtin_iterator(vm: &mut VirtualMachine, iter_obj: PyObjectRef) -> PyResult {
+    let next_value = match iter_obj.borrow().kind {
+        PyObjectKind::Iterator {
+            position,
+            ref iterated_obj,
+        } => next_from_sequence(vm, iterated_obj, position),
+        PyObjectKind::EnumerateIterator {
+            counter,
+            ref iterator,
+        } => match advance_or_stop(vm, iterator)? {
+            Some(value) => {
+                let tuple = vec![vm.context().new_int(counter), value];
+                Some(vm.context().new_tuple(tuple))
+            }
+            None => None,
+        },
+        PyObjectKind::ZipIterator { ref iterators } => {
+            // zip() with no iterables is an immediately-exhausted iterator,
+            // not an infinite generator of empty tuples.
+            if iterators.is_empty() {
+                None
+            } else {
+                let mut values = Vec::with_capacity(iterators.len());
+                let mut exhausted = false;
+                for iterator in iterators {
+                    match advance_or_stop(vm, iterator)? {
+                        Some(value) => values.push(value),
+                        None => {
+                            exhausted = true;
+                            break;
+                        }
+                    }
+                }
+                if exhausted {
+                    None
+                } else {
+                    Some(vm.context().new_tuple(values))
+                }
+            }
+        }
+        PyObjectKind::MapIterator {
+            ref mapper,
+            ref iterators,
+        } => {
+            let mut values = Vec::with_capacity(iterators.len());
+            let mut exhausted = false;
+            for iterator in iterators {
+                match advance_or_stop(vm, iterator)? {
+                    Some(value) => values.push(value),
+                    None => {
+                        exhausted = true;
+                        break;
+                    }
+                }
+            }
+            if exhausted {
+                None
+            } else {
+                let call_args = PyFuncArgs {
+                    args: values,
+                    kwargs: vec![],
+                };
+                Some(vm.invoke(mapper.clone(), call_args)?)
+            }
+        }
+        PyObjectKind::FilterIterator {
+            ref predicate,
+            ref iterator,
+        } => loop {
+            match advance_or_stop(vm, iterator)? {
+                Some(value) => {
+                    let is_none = match predicate.borrow().kind {
+                        PyObjectKind::None => true,
+                        _ => false,
+                    };
+                    let keep = if is_none {
+                        objbool::boolval(vm, value.clone())?
+                    } else {
+                        let call_args = PyFuncArgs {
+                            args: vec![value.clone()],
+                            kwargs: vec![],
+                        };
+                        objbool::boolval(vm, vm.invoke(predicate.clone(), call_args)?)?
+                    };
+                    if keep {
+                        break Some(value);
+                    }
+                }
+                None => break None,
+            }
+        },
+        _ => return Err(vm.new_type_error("object is not an iterator".to_string())),
+    };
+
+    match next_value {
+        Some(value) => {
+            advance_iterator(&iter_obj);
+            Ok(value)
+        }
+        None => Err(vm.new_stop_iteration()),
+    }
+}
+
+fn next_from_sequence(
+    vm: &mut VirtualMachine,
+    iterated_obj: &PyObjectRef,
+    position: usize,
+) -> Option<PyObjectRef> {
+    match iterated_obj.borrow().kind {
+        PyObjectKind::Range { start, stop, step } => {
+            let value = start + step * position as i32;
+            let in_range = if step > 0 { value < stop } else { value > stop };
+            if in_range {
+                Some(vm.context().new_int(value))
+            } else {
+                None
+            }
+        }
+        PyObjectKind::Tuple { ref elements } | PyObjectKind::List { ref elements } => {
+            elements.get(position).cloned()
+        }
+        PyObjectKind::String { ref value } => value
+            .chars()
+            .nth(position)
+            .map(|c| vm.new_str(c.to_string())),
+        _ => None,
+    }
+}
+
+fn advance_iterator(iter_obj: &PyObjectRef) {
+    match iter_obj.borrow_mut().kind {
+        PyObjectKind::Iterator { ref mut position, .. } => *position += 1,
+        PyObjectKind::EnumerateIterator { ref mut counter, .. } => *counter += 1,
+        _ => {}
+    }
+}
 
 fn builtin_len(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     arg_count_check!(vm, args, 1);
@@ -255,37 +650,142 @@ fn builtin_locals(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
 
 pub fn builtin_print(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
     trace!("print called with {:?}", args);
-    for a in args.args {
-        print!("{} ", a.borrow().str());
+
+    // sep/end/file/flush are keyword-only, so bind them against an
+    // args-less view of the call; the objects to print are handled
+    // separately below.
+    let kwonly_args = PyFuncArgs {
+        args: vec![],
+        kwargs: args.kwargs.clone(),
+    };
+    let bound = kwonly_args.bind(
+        vm,
+        &[
+            ("sep", Some(vm.new_str(" ".to_string()))),
+            ("end", Some(vm.new_str("\n".to_string()))),
+            ("file", Some(vm.get_none())),
+            ("flush", Some(vm.context().new_bool(false))),
+        ],
+    )?;
+
+    // A keyword given explicitly as `None` (e.g. `print(x, sep=None)`) means
+    // the same thing as leaving it out, same as CPython.
+    let sep = match bound["sep"].borrow().kind {
+        PyObjectKind::None => " ".to_string(),
+        _ => bound["sep"].borrow().str(),
+    };
+    let end = match bound["end"].borrow().kind {
+        PyObjectKind::None => "\n".to_string(),
+        _ => bound["end"].borrow().str(),
+    };
+    let file = match bound["file"].borrow().kind {
+        PyObjectKind::None => None,
+        _ => Some(bound["file"].clone()),
+    };
+
+    let text = args
+        .args
+        .iter()
+        .map(|a| a.borrow().str())
+        .collect::<Vec<_>>()
+        .join(&sep);
+
+    match file {
+        Some(ref file_obj) => {
+            let write = vm.get_attribute(file_obj.clone(), &"write".to_string())?;
+            let write_args = PyFuncArgs {
+                args: vec![vm.new_str(text + &end)],
+                kwargs: vec![],
+            };
+            vm.invoke(write, write_args)?;
+        }
+        None => {
+            print!("{}{}", text, end);
+        }
+    }
+
+    let should_flush = objbool::boolval(vm, bound["flush"].clone())?;
+    if should_flush {
+        match file {
+            Some(file_obj) => {
+                let flush = vm.get_attribute(file_obj, &"flush".to_string())?;
+                vm.invoke(flush, PyFuncArgs::default())?;
+            }
+            None => {
+                io::stdout().flush().unwrap();
+            }
+        }
     }
-    println!();
-    io::stdout().flush().unwrap();
+
     Ok(vm.get_none())
 }
 
-// builtin_map
+fn builtin_map(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    arg_count_check!(vm, args, 2, -1);
+    let mapper = args.args[0].clone();
+
+    let iterators: Result<Vec<PyObjectRef>, PyObjectRef> = args.args[1..]
+        .iter()
+        .map(|iterable| builtin_iter(vm, PyFuncArgs { args: vec![iterable.clone()], kwargs: vec![] }))
+        .collect();
+
+    Ok(PyObject::new(
+        PyObjectKind::MapIterator {
+            mapper,
+            iterators: iterators?,
+        },
+        vm.context().iter_type.clone(),
+    ))
+}
+
 // builtin_max
 // builtin_memoryview
 // builtin_min
-// builtin_next
 // builtin_object
 // builtin_oct
 // builtin_open
 // builtin_ord
 // builtin_pow
-// builtin_print
 // builtin_property
 
 fn builtin_range(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
-    arg_count_check!(vm, args, 1);
-    match args.args[0].borrow().kind {
-        PyObjectKind::Integer { ref value } => {
-            let range_elements: Vec<PyObjectRef> =
-                (0..*value).map(|num| vm.context().new_int(num)).collect();
-            Ok(vm.context().new_list(range_elements))
+    // range() takes start/stop/step positionally only; bind against an empty
+    // parameter list purely to reject any stray keyword argument.
+    PyFuncArgs {
+        args: vec![],
+        kwargs: args.kwargs.clone(),
+    }.bind(vm, &[])?;
+
+    arg_count_check!(vm, args, 1, 3);
+    let args = args.args;
+
+    let int_value = |vm: &mut VirtualMachine, obj: &PyObjectRef| -> Result<i32, PyObjectRef> {
+        match obj.borrow().kind {
+            PyObjectKind::Integer { value } => Ok(value),
+            _ => Err(vm.new_type_error(
+                "'range' arguments must be integers".to_string(),
+            )),
         }
-        _ => panic!("first argument to range must be an integer"),
+    };
+
+    let (start, stop, step) = match args.len() {
+        1 => (0, int_value(vm, &args[0])?, 1),
+        2 => (int_value(vm, &args[0])?, int_value(vm, &args[1])?, 1),
+        _ => (
+            int_value(vm, &args[0])?,
+            int_value(vm, &args[1])?,
+            int_value(vm, &args[2])?,
+        ),
+    };
+
+    if step == 0 {
+        return Err(vm.new_type_error("range() arg 3 must not be zero".to_string()));
     }
+
+    Ok(PyObject::new(
+        PyObjectKind::Range { start, stop, step },
+        vm.context().range_type.clone(),
+    ))
 }
 
 // builtin_repr
@@ -314,7 +814,22 @@ fn builtin_setattr(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
 // builtin_sum
 // builtin_super
 // builtin_vars
-// builtin_zip
+
+fn builtin_zip(vm: &mut VirtualMachine, args: PyFuncArgs) -> PyResult {
+    let iterators: Result<Vec<PyObjectRef>, PyObjectRef> = args
+        .args
+        .iter()
+        .map(|iterable| builtin_iter(vm, PyFuncArgs { args: vec![iterable.clone()], kwargs: vec![] }))
+        .collect();
+
+    Ok(PyObject::new(
+        PyObjectKind::ZipIterator {
+            iterators: iterators?,
+        },
+        vm.context().iter_type.clone(),
+    ))
+}
+
 // builtin___import__
 
 pub fn make_module(ctx: &PyContext) -> PyObjectRef {
@@ -327,7 +842,10 @@ pub fn make_module(ctx: &PyContext) -> PyObjectRef {
     dict.insert(String::from("compile"), ctx.new_rustfunc(builtin_compile));
     dict.insert(String::from("dict"), ctx.dict_type.clone());
     dict.insert(String::from("dir"), ctx.new_rustfunc(builtin_dir));
+    dict.insert(String::from("enumerate"), ctx.new_rustfunc(builtin_enumerate));
     dict.insert(String::from("eval"), ctx.new_rustfunc(builtin_eval));
+    dict.insert(String::from("exec"), ctx.new_rustfunc(builtin_exec));
+    dict.insert(String::from("filter"), ctx.new_rustfunc(builtin_filter));
     dict.insert(String::from("getattr"), ctx.new_rustfunc(builtin_getattr));
     dict.insert(String::from("hasattr"), ctx.new_rustfunc(builtin_hasattr));
     dict.insert(String::from("id"), ctx.new_rustfunc(builtin_id));
@@ -336,9 +854,12 @@ pub fn make_module(ctx: &PyContext) -> PyObjectRef {
         String::from("isinstance"),
         ctx.new_rustfunc(builtin_isinstance),
     );
+    dict.insert(String::from("iter"), ctx.new_rustfunc(builtin_iter));
     dict.insert(String::from("len"), ctx.new_rustfunc(builtin_len));
     dict.insert(String::from("list"), ctx.list_type.clone());
     dict.insert(String::from("locals"), ctx.new_rustfunc(builtin_locals));
+    dict.insert(String::from("map"), ctx.new_rustfunc(builtin_map));
+    dict.insert(String::from("next"), ctx.new_rustfunc(builtin_next));
     dict.insert(String::from("print"), ctx.new_rustfunc(builtin_print));
     dict.insert(String::from("range"), ctx.new_rustfunc(builtin_range));
     dict.insert(String::from("setattr"), ctx.new_rustfunc(builtin_setattr));
@@ -346,6 +867,7 @@ pub fn make_module(ctx: &PyContext) -> PyObjectRef {
     dict.insert(String::from("tuple"), ctx.tuple_type.clone());
     dict.insert(String::from("type"), ctx.type_type.clone());
     dict.insert(String::from("object"), ctx.object.clone());
+    dict.insert(String::from("zip"), ctx.new_rustfunc(builtin_zip));
 
     // Exceptions:
     dict.insert(
@@ -378,6 +900,10 @@ pub fn make_module(ctx: &PyContext) -> PyObjectRef {
         String::from("ValueError"),
         ctx.exceptions.value_error.clone(),
     );
+    dict.insert(
+        String::from("StopIteration"),
+        ctx.exceptions.stop_iteration.clone(),
+    );
 
     let d2 = PyObject::new(PyObjectKind::Dict { elements: dict }, ctx.type_type.clone());
     let scope = PyObject::new(
@@ -415,7 +941,203 @@ pub fn builtin_build_class_(vm: &mut VirtualMachine, mut args: PyFuncArgs) -> Py
         function,
         PyFuncArgs {
             args: vec![namespace.clone()],
+            kwargs: vec![],
         },
     );
     objtype::new(metaclass, name, bases, namespace)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_dict(entries: Vec<(&str, PyObjectRef)>) -> PyObjectRef {
+        let mut elements = HashMap::new();
+        for (key, value) in entries {
+            elements.insert(key.to_string(), value);
+        }
+        PyObject {
+            kind: PyObjectKind::Dict { elements },
+            typ: None,
+        }.into_ref()
+    }
+
+    #[test]
+    fn object_attribute_names_reads_a_plain_dict() {
+        let none = PyObject {
+            kind: PyObjectKind::None,
+            typ: None,
+        }.into_ref();
+        let dict = new_dict(vec![("a", none.clone()), ("b", none)]);
+
+        let mut names = object_attribute_names(&dict);
+        names.sort();
+        assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn object_attribute_names_follows_the_instance_dict_indirection() {
+        let none = PyObject {
+            kind: PyObjectKind::None,
+            typ: None,
+        }.into_ref();
+        let dict = new_dict(vec![("value", none)]);
+        let instance = PyObject {
+            kind: PyObjectKind::Instance { dict },
+            typ: None,
+        }.into_ref();
+
+        assert_eq!(object_attribute_names(&instance), vec!["value".to_string()]);
+    }
+
+    #[test]
+    fn object_attribute_names_is_empty_for_unrelated_kinds() {
+        let integer = PyObject {
+            kind: PyObjectKind::Integer { value: 42 },
+            typ: None,
+        }.into_ref();
+
+        assert!(object_attribute_names(&integer).is_empty());
+    }
+
+    fn none_obj() -> PyObjectRef {
+        PyObject {
+            kind: PyObjectKind::None,
+            typ: None,
+        }.into_ref()
+    }
+
+    #[test]
+    fn bind_rejects_an_argument_given_both_positionally_and_by_keyword() {
+        let mut vm = VirtualMachine::new();
+        let args = PyFuncArgs {
+            args: vec![none_obj()],
+            kwargs: vec![("x".to_string(), none_obj())],
+        };
+
+        let result = args.bind(&mut vm, &[("x", None)]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bind_errors_on_missing_required_argument() {
+        let mut vm = VirtualMachine::new();
+        let args = PyFuncArgs {
+            args: vec![],
+            kwargs: vec![],
+        };
+
+        let result = args.bind(&mut vm, &[("x", None)]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bind_errors_on_unexpected_keyword_argument() {
+        let mut vm = VirtualMachine::new();
+        let args = PyFuncArgs {
+            args: vec![],
+            kwargs: vec![("surprise".to_string(), none_obj())],
+        };
+
+        let result = args.bind(&mut vm, &[]);
+        assert!(result.is_err());
+    }
+
+    fn dir_list_names(list: PyObjectRef) -> Vec<String> {
+        match list.borrow().kind {
+            PyObjectKind::List { ref elements } => {
+                elements.iter().map(|e| e.borrow().str()).collect()
+            }
+            _ => panic!("dir() should return a list"),
+        }
+    }
+
+    #[test]
+    fn dir_on_a_class_walks_its_own_bases_not_the_metaclass() {
+        let mut vm = VirtualMachine::new();
+
+        // The metaclass only exists to prove dir_object doesn't walk it;
+        // "type_only_attr" must not leak into dir(Derived).
+        let metaclass = PyObject {
+            kind: PyObjectKind::Class {
+                name: "type".to_string(),
+                dict: new_dict(vec![("type_only_attr", none_obj())]),
+                mro: vec![],
+            },
+            typ: None,
+        }.into_ref();
+
+        let base = PyObject {
+            kind: PyObjectKind::Class {
+                name: "Base".to_string(),
+                dict: new_dict(vec![("base_attr", none_obj())]),
+                mro: vec![],
+            },
+            typ: Some(metaclass.clone()),
+        }.into_ref();
+
+        let derived = PyObject {
+            kind: PyObjectKind::Class {
+                name: "Derived".to_string(),
+                dict: new_dict(vec![("own_attr", none_obj())]),
+                mro: vec![base.clone()],
+            },
+            typ: Some(metaclass),
+        }.into_ref();
+
+        let names = dir_list_names(dir_object(&mut vm, derived));
+
+        assert!(names.contains(&"own_attr".to_string()));
+        assert!(names.contains(&"base_attr".to_string()));
+        assert!(!names.contains(&"type_only_attr".to_string()));
+    }
+
+    #[test]
+    fn advance_or_stop_propagates_non_stop_iteration_errors() {
+        let mut vm = VirtualMachine::new();
+        // Not one of the builtin iterator kinds, so next_builtin_iterator
+        // raises a plain TypeError rather than StopIteration.
+        let not_an_iterator = PyObject {
+            kind: PyObjectKind::None,
+            typ: None,
+        }.into_ref();
+
+        let err = advance_or_stop(&mut vm, &not_an_iterator)
+            .expect_err("a non-iterator should error, not report exhaustion");
+        assert!(!is_stop_iteration(&mut vm, &err));
+    }
+
+    #[test]
+    fn builtin_iter_rejects_non_iterable_objects() {
+        let mut vm = VirtualMachine::new();
+        let integer = PyObject {
+            kind: PyObjectKind::Integer { value: 1 },
+            typ: None,
+        }.into_ref();
+
+        let result = builtin_iter(
+            &mut vm,
+            PyFuncArgs {
+                args: vec![integer],
+                kwargs: vec![],
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn zip_with_no_iterables_is_immediately_exhausted() {
+        let mut vm = VirtualMachine::new();
+        let zip_iter = builtin_zip(
+            &mut vm,
+            PyFuncArgs {
+                args: vec![],
+                kwargs: vec![],
+            },
+        ).expect("zip() with no arguments should succeed");
+
+        let err = next_builtin_iterator(&mut vm, zip_iter)
+            .expect_err("an empty zip() should be exhausted, not loop forever");
+        assert!(is_stop_iteration(&mut vm, &err));
+    }
+}